@@ -8,6 +8,10 @@ const MAX_TIME: u32 = 1710;
 const SEPARATION: u32 = 300;
 const CHANNEL_COUNT: usize = 8;
 
+// A gap longer than this is treated as the frame boundary rather than a
+// channel pulse. Comfortably above MAX_TIME + SEPARATION (2010us).
+const DEFAULT_SYNC_THRESHOLD_US: u32 = 3_000;
+
 #[derive(Clone)]
 pub struct PpmFrame<TIME> {
     signal_timings: [TIME; CHANNEL_COUNT],
@@ -113,3 +117,160 @@ where
         }
     }
 }
+
+/**
+  Reconstructs a channel array from the inter-edge durations of an
+  incoming PPM stream, the mirror image of [`CppmWriter`] on the input
+  side. Durations are captured by an input-capture timer as `TIME`
+  values and converted to microseconds via `time_to_us`.
+
+  A gap wider than `sync_threshold_us` is treated as the frame boundary:
+  it resets the channel index, and returns the decoded channels if a
+  full frame (`CHANNEL_COUNT` pulses) was seen since the last gap. A
+  short frame (a dropped pulse, a glitch, ...) is discarded instead of
+  being returned, so a single bad edge can't permanently misalign which
+  channel index a pulse is attributed to.
+*/
+pub struct CppmReader<TIME, F>
+where
+    F: Fn(TIME) -> u32
+{
+    time_to_us: F,
+    sync_threshold_us: u32,
+    channels: [f32; CHANNEL_COUNT],
+    index: usize,
+    _timer: PhantomData<TIME>
+}
+
+impl<TIME, F> CppmReader<TIME, F>
+where
+    F: Fn(TIME) -> u32
+{
+    pub fn new(time_to_us: F, sync_threshold_us: u32) -> Self {
+        Self {
+            time_to_us,
+            sync_threshold_us,
+            channels: [0.0; CHANNEL_COUNT],
+            index: 0,
+            _timer: PhantomData,
+        }
+    }
+
+    /// Like [`new`](Self::new), but with [`DEFAULT_SYNC_THRESHOLD_US`]
+    /// as the sync gap threshold.
+    pub fn with_default_threshold(time_to_us: F) -> Self {
+        Self::new(time_to_us, DEFAULT_SYNC_THRESHOLD_US)
+    }
+
+    /// Feed in the duration since the previous edge. Returns the decoded
+    /// channel values once a full frame has been captured.
+    pub fn push_edge(&mut self, duration: TIME) -> Option<[f32; CHANNEL_COUNT]> {
+        let duration_us = (self.time_to_us)(duration);
+
+        if duration_us > self.sync_threshold_us {
+            let frame = if self.index == CHANNEL_COUNT {
+                Some(self.channels)
+            }
+            else {
+                // A pulse went missing somewhere during the last frame;
+                // discard it rather than misattributing the channels
+                // that follow.
+                None
+            };
+            self.index = 0;
+            frame
+        }
+        else {
+            if self.index < CHANNEL_COUNT {
+                // Every captured channel pulse is preceded by the fixed
+                // 300us SEPARATION low pulse CppmWriter always drives
+                // before a signal timing, so that has to come off
+                // before inverting `PpmFrame::from_channels`'s formula.
+                let normalized = (duration_us as f32 - SEPARATION as f32 - MIN_TIME as f32)
+                    / (MAX_TIME - MIN_TIME) as f32;
+                self.channels[self.index] = normalized.clamp(0.0, 1.0);
+                self.index += 1;
+            }
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use super::*;
+
+    fn identity(us: u32) -> u32 {
+        us
+    }
+
+    fn assert_channels_approx_eq(expected: [f32; CHANNEL_COUNT], actual: [f32; CHANNEL_COUNT]) {
+        for (e, a) in expected.iter().zip(actual.iter()) {
+            assert!((e - a).abs() < 0.01, "expected {:?}, got {:?}", expected, actual);
+        }
+    }
+
+    #[test]
+    fn reader_decodes_a_full_frame_from_from_channels_output() {
+        let channels = [0.0, 0.25, 0.5, 0.75, 1.0, 0.2, 0.8, 0.5];
+        let frame = PpmFrame::from_channels(channels, identity);
+        let mut reader = CppmReader::with_default_threshold(identity);
+
+        let mut decoded = None;
+        for &time in frame.signal_timings.iter() {
+            decoded = reader.push_edge(SEPARATION + time);
+            assert_matches!(decoded, None);
+        }
+        // The pause before the next frame's first pulse is the sync gap.
+        decoded = reader.push_edge(SEPARATION + frame.frame_padding);
+
+        assert_channels_approx_eq(channels, decoded.expect("Expected a decoded frame"));
+    }
+
+    #[test]
+    fn reader_discards_a_short_frame_without_misaligning_later_channels() {
+        let channels = [0.5; CHANNEL_COUNT];
+        let frame = PpmFrame::from_channels(channels, identity);
+        let mut reader = CppmReader::with_default_threshold(identity);
+
+        // A glitch drops the last three pulses of the frame.
+        for &time in frame.signal_timings.iter().take(5) {
+            assert_matches!(reader.push_edge(SEPARATION + time), None);
+        }
+        let discarded = reader.push_edge(SEPARATION + frame.frame_padding);
+        assert_matches!(discarded, None);
+
+        // The next full frame still decodes correctly, proving the
+        // glitch didn't leave the channel index misaligned.
+        let mut decoded = None;
+        for &time in frame.signal_timings.iter() {
+            decoded = reader.push_edge(SEPARATION + time);
+            assert_matches!(decoded, None);
+        }
+        decoded = reader.push_edge(SEPARATION + frame.frame_padding);
+
+        assert_channels_approx_eq(channels, decoded.expect("Expected a decoded frame"));
+    }
+
+    #[test]
+    fn reader_treats_any_gap_past_the_threshold_as_a_sync_reset() {
+        let mut reader = CppmReader::with_default_threshold(identity);
+
+        // Two sync gaps in a row, with no pulses in between, should
+        // just keep resetting rather than panicking or emitting a
+        // spurious frame.
+        assert_matches!(reader.push_edge(DEFAULT_SYNC_THRESHOLD_US + 1), None);
+        assert_matches!(reader.push_edge(DEFAULT_SYNC_THRESHOLD_US + 1), None);
+
+        let channels = [0.1, 0.9, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5];
+        let frame = PpmFrame::from_channels(channels, identity);
+        let mut decoded = None;
+        for &time in frame.signal_timings.iter() {
+            decoded = reader.push_edge(SEPARATION + time);
+        }
+        decoded = reader.push_edge(DEFAULT_SYNC_THRESHOLD_US + 1);
+
+        assert_channels_approx_eq(channels, decoded.expect("Expected a decoded frame"));
+    }
+}
@@ -0,0 +1,428 @@
+use heapless::{Vec, spsc::{Producer, Consumer}};
+use heapless::consts::*;
+
+const CHANNEL_AMOUNT: u8 = 16;
+const CHANNEL_BYTE_COUNT: usize = 22;
+
+const FRAME_TYPE_RC_CHANNELS: u8 = 0x16;
+
+// `len` (the second frame byte) counts `type + payload + crc`, so the
+// largest frame we can buffer is addr + len + 62 payload/crc bytes.
+const MAX_FRAME_BYTES: usize = 62;
+
+// Addresses actually seen on a CRSF bus: broadcast, flight controller,
+// CRSF receiver, and the handset/radio.
+const VALID_ADDRESSES: [u8; 4] = [0x00, 0xc8, 0xea, 0xee];
+
+fn is_valid_addr(byte: u8) -> bool {
+    VALID_ADDRESSES.contains(&byte)
+}
+
+// How many consecutive bytes we're willing to reject as addresses
+// before giving up and latching `desynced`, mirroring the
+// `RESYNC_LIMIT` that `SbusDeframer` uses.
+const RESYNC_LIMIT: usize = 128;
+
+#[derive(Debug, PartialEq)]
+pub enum Error<E> {
+    /// The CRC8 computed over `type` and the payload didn't match the
+    /// CRC byte that closed the frame.
+    InvalidCrc,
+    /// The frame decoded fine, but we don't know how to turn this frame
+    /// type into a [`CrsfFrame`].
+    UnsupportedFrameType(u8),
+    /// `len` was too short to cover `type + crc`, or claimed a payload
+    /// larger than we're willing to buffer.
+    FrameTooLong,
+    /// The sync/address byte didn't match any known CRSF address.
+    InvalidAddr(u8),
+    /// The frame's `len` didn't match the payload a known frame type expects.
+    UnexpectedPayloadLength,
+    ByteReadError(E),
+}
+
+pub type RecoverableResult<T, E> = core::result::Result<T, Error<E>>;
+pub type ProcessingResult<T, E> = core::result::Result<T, FatalError<E>>;
+
+#[derive(Debug)]
+pub enum FatalError<E> {
+    ResultTxFull(RecoverableResult<CrsfFrame, E>),
+    VecFull(u8),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CrsfFrame {
+    pub channels: [u16; 16],
+}
+
+/**
+  Decodes a stream of CRSF (Crossfire) bytes, read one at a time from
+  `byte_rx`, into [`CrsfFrame`]s sent over `result_tx`. Mirrors the
+  state-machine / spsc-queue design of [`crate::sbus::SbusDecoder`].
+*/
+pub struct CrsfDecoder<'a, E> {
+    byte_rx: Consumer<'a, core::result::Result<u8, E>, U32>,
+    result_tx: Producer<'a, RecoverableResult<CrsfFrame, E>, U8>,
+    state: DecoderState,
+    desync_count: usize,
+}
+
+impl<'a, E> CrsfDecoder<'a, E> {
+    pub fn new(
+        byte_rx: Consumer<'a, core::result::Result<u8, E>, U32>,
+        result_tx: Producer<'a, RecoverableResult<CrsfFrame, E>, U8>
+    ) -> Self {
+        Self {
+            byte_rx,
+            result_tx,
+            state: DecoderState::WaitForAddr,
+            desync_count: 0,
+        }
+    }
+
+    /// Set once more than `RESYNC_LIMIT` consecutive bytes were rejected
+    /// as an address, and stays set after that. Once set, the caller
+    /// should give up on this stream.
+    pub fn desynced(&self) -> bool {
+        self.desync_count > RESYNC_LIMIT
+    }
+
+    /**
+      Process the bytes that have been sent over the byte channel. If a
+      full frame has been received, or some bytes were invalid, the
+      frame or error are sent over the message channel.
+
+      If the message can't be sent over the message channel, an error is
+      returned.
+    */
+    pub fn process(&mut self) -> ProcessingResult<DecoderState, E> {
+        loop {
+            let byte = match self.byte_rx.dequeue() {
+                Some(byte) => match byte {
+                    Ok(byte) => byte,
+                    Err(e) => {
+                        self.state = DecoderState::WaitForAddr;
+                        self.try_send_message(Err(Error::ByteReadError(e)))?;
+                        continue
+                    }
+                },
+                None => break Ok(self.state.clone())
+            };
+
+            let new_state = match self.state.clone() {
+                DecoderState::WaitForAddr => {
+                    self.wait_for_addr_state(byte)?
+                }
+                DecoderState::WaitForLen => {
+                    self.wait_for_len_state(byte)?
+                }
+                DecoderState::Payload(previous_bytes, len) => {
+                    self.payload_state(byte, previous_bytes, len)?
+                }
+            };
+
+            self.state = new_state;
+        }
+    }
+
+    fn wait_for_addr_state(&mut self, byte: u8) -> ProcessingResult<DecoderState, E> {
+        if is_valid_addr(byte) {
+            // Once desynced, stay desynced.
+            if self.desync_count <= RESYNC_LIMIT {
+                self.desync_count = 0;
+            }
+            Ok(DecoderState::WaitForLen)
+        }
+        else {
+            self.desync_count += 1;
+            self.try_send_message(Err(Error::InvalidAddr(byte)))?;
+            Ok(DecoderState::WaitForAddr)
+        }
+    }
+
+    fn wait_for_len_state(&mut self, byte: u8) -> ProcessingResult<DecoderState, E> {
+        // `len` must at least cover `type + crc`.
+        if byte < 2 || byte as usize > MAX_FRAME_BYTES {
+            self.try_send_message(Err(Error::FrameTooLong))?;
+            Ok(DecoderState::WaitForAddr)
+        }
+        else {
+            Ok(DecoderState::Payload(Vec::default(), byte))
+        }
+    }
+
+    fn payload_state(&mut self, byte: u8, mut previous_bytes: Vec<u8, U62>, len: u8)
+        -> ProcessingResult<DecoderState, E>
+    {
+        if previous_bytes.len() + 1 < len as usize {
+            self.try_push_byte(byte, &mut previous_bytes)?;
+            Ok(DecoderState::Payload(previous_bytes, len))
+        }
+        else {
+            self.try_push_byte(byte, &mut previous_bytes)?;
+            // This was the crc byte, the frame is complete.
+            self.try_send_message(decode_crsf(&previous_bytes))?;
+            Ok(DecoderState::WaitForAddr)
+        }
+    }
+
+    fn try_send_message(&mut self, message: RecoverableResult<CrsfFrame, E>)
+        -> ProcessingResult<(), E>
+    {
+        if let Err(message) = self.result_tx.enqueue(message) {
+            self.state = DecoderState::WaitForAddr;
+            Err(FatalError::ResultTxFull(message))
+        }
+        else {
+            Ok(())
+        }
+    }
+
+    fn try_push_byte<S>(&mut self, byte: u8, target: &mut Vec<u8, S>)
+        -> ProcessingResult<(), E>
+        where S: heapless::ArrayLength<u8>
+    {
+        if let Err(byte) = target.push(byte) {
+            self.state = DecoderState::WaitForAddr;
+            Err(FatalError::VecFull(byte))
+        }
+        else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum DecoderState {
+    WaitForAddr,
+    WaitForLen,
+    /// Collecting `type + payload + crc`. Keeps track of the bytes
+    /// received so far and the total length (as given by the frame's
+    /// `len` byte) we're waiting for.
+    Payload(Vec<u8, U62>, u8),
+}
+
+// `bytes` is `type + payload + crc`, i.e. everything `len` counted.
+fn decode_crsf<E>(bytes: &[u8]) -> RecoverableResult<CrsfFrame, E> {
+    let (type_and_payload, crc_byte) = bytes.split_at(bytes.len() - 1);
+    if crc8(type_and_payload) != crc_byte[0] {
+        return Err(Error::InvalidCrc);
+    }
+
+    let frame_type = type_and_payload[0];
+    let payload = &type_and_payload[1..];
+
+    match frame_type {
+        FRAME_TYPE_RC_CHANNELS if payload.len() == CHANNEL_BYTE_COUNT => Ok(CrsfFrame {
+            channels: decode_channels(payload),
+        }),
+        FRAME_TYPE_RC_CHANNELS => Err(Error::UnexpectedPayloadLength),
+        other => Err(Error::UnsupportedFrameType(other)),
+    }
+}
+
+// CRC8/DVB-S2: polynomial 0xD5, initial value 0, processed MSB-first.
+fn crc8(data: &[u8]) -> u8 {
+    let mut crc = 0u8;
+    for &byte in data {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0xD5
+            }
+            else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+// Packs sixteen 11-bit channel values LSB-first, exactly like SBUS:
+// channel `c` starts at bit `c * 11`.
+fn decode_channels(bytes: &[u8]) -> [u16; 16] {
+    let mut channels = [0u16; 16];
+    for channel in 0..CHANNEL_AMOUNT {
+        let offset = channel * 11;
+        let first_byte_offset = offset % 8;
+        let first_byte_index = (offset / 8) as usize;
+
+        let bits_from_next_bytes = 11 - (8 - first_byte_offset);
+
+        let first_byte_mask = 0xff << first_byte_offset;
+        let second_byte_mask = 0xff >> (8 - bits_from_next_bytes.min(8));
+
+        let from_first_byte =
+            ( (bytes[first_byte_index] & first_byte_mask)
+              >> first_byte_offset
+            ) as u16;
+        let from_second_byte =
+            ( (bytes[1 + first_byte_index] & second_byte_mask) as u16
+            ) << (8 - first_byte_offset);
+
+        let from_third_byte = if bits_from_next_bytes > 8 {
+            let bits_from_third_byte = bits_from_next_bytes - 8;
+            let third_byte_mask = 0xff >> (8 - bits_from_third_byte);
+            ( (bytes[2 + first_byte_index] & third_byte_mask) as u16
+            ) << (11 - bits_from_third_byte)
+        }
+        else {
+            0
+        };
+
+        channels[channel as usize]
+            = from_first_byte
+            | from_second_byte
+            | from_third_byte
+    }
+    channels
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use pretty_assertions::assert_eq;
+    use super::*;
+
+    use heapless::spsc::Queue;
+
+    // addr, len, type (RC channels), 22 channel bytes, crc
+    fn rc_channels_frame() -> [u8; 26] {
+        let channel_bytes: [u8; CHANNEL_BYTE_COUNT] = [
+            0b1111_1110,
+            0b0000_0111,
+            0b1100_0000,
+            0b1111_1111,
+            0b0000_0001,
+            0b1111_0000,
+            0b0111_1111,
+            0b0000_0000,
+            0b1111_1100,
+            0b0001_1111,
+            0b0000_0000,
+            0b1111_1111,
+            0b0000_0111,
+            0b1100_0000,
+            0b1111_1111,
+            0b0000_0001,
+            0b1111_0000,
+            0b0111_1111,
+            0b0000_0000,
+            0b1111_1100,
+            0b0001_1111,
+            0b0000_0000,
+        ];
+
+        let mut type_and_payload = [0u8; 1 + CHANNEL_BYTE_COUNT];
+        type_and_payload[0] = FRAME_TYPE_RC_CHANNELS;
+        type_and_payload[1..].copy_from_slice(&channel_bytes);
+        let crc = crc8(&type_and_payload);
+
+        let mut frame = [0u8; 26];
+        frame[0] = 0xc8;
+        frame[1] = (1 + CHANNEL_BYTE_COUNT + 1) as u8;
+        frame[2..25].copy_from_slice(&type_and_payload);
+        frame[25] = crc;
+        frame
+    }
+
+    #[test]
+    fn crsf_decoder_decodes_a_valid_rc_channels_frame() {
+        let mut byte_queue = Queue::new();
+        let (mut byte_producer, byte_consumer) = byte_queue.split();
+
+        let mut message_queue = Queue::new();
+        let (message_producer, mut message_consumer) = message_queue.split();
+        let mut decoder = CrsfDecoder::<()>::new(byte_consumer, message_producer);
+
+        for byte in &rc_channels_frame() {
+            byte_producer.enqueue(Ok(*byte)).unwrap();
+        }
+
+        let result = decoder.process().expect("Decode error");
+        assert_matches!(result, DecoderState::WaitForAddr);
+
+        let decoded = message_consumer.dequeue()
+            .expect("Expected a message")
+            .expect("Expected message not to be Err");
+
+        assert_eq!(decoded.channels[0], 0b111_1111_1110);
+        assert_eq!(decoded.channels[1], 0);
+        assert_eq!(decoded.channels[2], 0b111_1111_1111);
+    }
+
+    #[test]
+    fn crsf_decoder_rejects_a_bad_crc_and_resyncs() {
+        let mut byte_queue = Queue::new();
+        let (mut byte_producer, byte_consumer) = byte_queue.split();
+
+        let mut message_queue = Queue::new();
+        let (message_producer, mut message_consumer) = message_queue.split();
+        let mut decoder = CrsfDecoder::<()>::new(byte_consumer, message_producer);
+
+        let mut corrupted = rc_channels_frame();
+        let last = corrupted.len() - 1;
+        corrupted[last] ^= 0xff;
+
+        for byte in &corrupted {
+            byte_producer.enqueue(Ok(*byte)).unwrap();
+        }
+        decoder.process().unwrap();
+
+        let decoded = message_consumer.dequeue();
+        assert_eq!(decoded, Some(Err(Error::InvalidCrc)));
+
+        for byte in &rc_channels_frame() {
+            byte_producer.enqueue(Ok(*byte)).unwrap();
+        }
+        decoder.process().unwrap();
+
+        let decoded = message_consumer.dequeue()
+            .expect("Expected a message")
+            .expect("Expected message not to be Err");
+        assert_eq!(decoded.channels[0], 0b111_1111_1110);
+    }
+
+    #[test]
+    fn crsf_decoder_rejects_invalid_addresses_and_resyncs() {
+        let mut byte_queue = Queue::new();
+        let (mut byte_producer, byte_consumer) = byte_queue.split();
+
+        let mut message_queue = Queue::new();
+        let (message_producer, mut message_consumer) = message_queue.split();
+        let mut decoder = CrsfDecoder::<()>::new(byte_consumer, message_producer);
+
+        byte_producer.enqueue(Ok(0x42)).unwrap();
+        decoder.process().unwrap();
+        assert_eq!(message_consumer.dequeue(), Some(Err(Error::InvalidAddr(0x42))));
+        assert!(!decoder.desynced());
+
+        for byte in &rc_channels_frame() {
+            byte_producer.enqueue(Ok(*byte)).unwrap();
+        }
+        decoder.process().unwrap();
+
+        let decoded = message_consumer.dequeue()
+            .expect("Expected a message")
+            .expect("Expected message not to be Err");
+        assert_eq!(decoded.channels[0], 0b111_1111_1110);
+    }
+
+    #[test]
+    fn crsf_decoder_latches_desynced_when_resync_limit_is_exceeded() {
+        let mut byte_queue = Queue::new();
+        let (mut byte_producer, byte_consumer) = byte_queue.split();
+
+        let mut message_queue = Queue::new();
+        let (message_producer, mut message_consumer) = message_queue.split();
+        let mut decoder = CrsfDecoder::<()>::new(byte_consumer, message_producer);
+
+        for _ in 0..(RESYNC_LIMIT + 10) {
+            let _ = byte_producer.enqueue(Ok(0x42));
+            let _ = decoder.process();
+            let _ = message_consumer.dequeue();
+        }
+
+        assert!(decoder.desynced());
+    }
+}
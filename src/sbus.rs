@@ -26,10 +26,12 @@ pub struct SbusFrame {
     pub digital_channels: [bool; 2],
 }
 
+// `Channel`'s byte vector has capacity `CHANNEL_BYTE_COUNT + 1`, exactly
+// the number of bytes `advance` ever pushes into it, so pushing can't
+// fail and there is no `VecFull`-style variant here.
 #[derive(Debug)]
 pub enum FatalError<E> {
     ResultTxFull(RecoverableResult<SbusFrame, E>),
-    VecFull(u8),
 }
 
 
@@ -87,86 +89,13 @@ impl<'a, E> SbusDecoder<'a, E> {
                 None => break Ok(self.state.clone())
             };
 
-            let new_state = match self.state.clone() {
-                DecoderState::WaitForHeader => {
-                    self.wait_for_header(byte)?
-                }
-                DecoderState::Channel(previous_bytes) => {
-                    self.channel_state(byte, previous_bytes)?
-                }
-                DecoderState::WaitForFooter(result) => {
-                    self.wait_for_footer_state(byte, result)?
-                }
-                DecoderState::Recover => {
-                    self.recover_state(byte)
-                }
-            };
-
-            // Update the state
+            // Advance the shared state machine by one byte, then relay
+            // whatever event (if any) it produced.
+            let (new_state, event) = advance(self.state.clone(), byte);
             self.state = new_state;
-        }
-    }
-
-    // Handle bytes being received in the recover state
-    fn recover_state(&mut self, byte: u8) -> DecoderState {
-        // We need to see a sequence of FOOTER->HEADER to know that
-        // we are in a valid state
-        if byte == FOOTER_BYTE {
-            DecoderState::WaitForHeader
-        }
-        else {
-            DecoderState::Recover
-        }
-    }
-
-    fn wait_for_footer_state(
-        &mut self,
-        byte: u8,
-        frame: Result<SbusFrame, Failsafe>
-    ) -> ProcessingResult<DecoderState, E> {
-        if byte == FOOTER_BYTE {
-            let result = match frame {
-                Ok(frame) => Ok(frame),
-                Err(Failsafe{frame}) => Err(Error::Failsafe(frame)),
-            };
-            self.try_send_message(result)?;
-
-            // Wait for the next frame
-            Ok(DecoderState::WaitForHeader)
-        }
-        else {
-            // We did not get a stop byte, try to relay that error
-            self.try_send_message(Err(Error::MissingFooter))?;
-            Ok(DecoderState::Recover)
-        }
-    }
-
-    fn channel_state(&mut self, byte: u8, mut previous_bytes: Vec<u8, U23>)
-        -> ProcessingResult<DecoderState, E>
-    {
-        if previous_bytes.len() < CHANNEL_BYTE_COUNT {
-            self.try_push_byte(byte, &mut previous_bytes)?;
-            // We are still expecting more bytes with channel values,
-            // try to decode and store them.
-            Ok(DecoderState::Channel(previous_bytes))
-        }
-        else {
-            self.try_push_byte(byte, &mut previous_bytes)?;
-            // This was the last channel byte, decode channels
-            // and wait for footer
-            Ok(DecoderState::WaitForFooter(decode_sbus(previous_bytes)))
-        }
-    }
-
-    fn wait_for_header(&mut self, byte: u8) -> ProcessingResult<DecoderState, E> {
-        if byte == HEADER_BYTE {
-            Ok(DecoderState::Channel(Vec::default()))
-        }
-        else {
-            // We expected a header byte but it did not arrive, go into
-            // recovery mode
-            self.try_send_message(Err(Error::MissingHeader))?;
-            Ok(DecoderState::Recover)
+            if let Some(event) = event {
+                self.try_send_message(core_event_to_result(event))?;
+            }
         }
     }
 
@@ -181,18 +110,6 @@ impl<'a, E> SbusDecoder<'a, E> {
             Ok(())
         }
     }
-    fn try_push_byte<S>(&mut self, byte: u8, target: &mut Vec<u8, S>)
-        -> ProcessingResult<(), E>
-        where S: heapless::ArrayLength<u8>
-    {
-        if let Err(byte) = target.push(byte) {
-            self.state = DecoderState::Recover;
-            Err(FatalError::VecFull(byte))
-        }
-        else {
-            Ok(())
-        }
-    }
 }
 
 #[derive(Clone, Debug)]
@@ -209,6 +126,148 @@ pub enum DecoderState {
     Recover
 }
 
+// What happened while advancing the decode state machine by one byte,
+// if anything worth relaying to the caller.
+#[derive(Clone, Debug)]
+enum CoreEvent {
+    MissingHeader,
+    MissingFooter,
+    Frame(Result<SbusFrame, Failsafe>),
+}
+
+/**
+  Advance the SBUS decode state machine by a single byte. This is the
+  transport-agnostic core shared by [`SbusDecoder`] (byte-at-a-time over
+  an spsc queue) and the `async`-feature [`Stream`](futures::Stream)
+  front-end: both front-ends differ only in where the next byte comes
+  from, not in how framing and channel decoding work.
+*/
+fn advance(state: DecoderState, byte: u8) -> (DecoderState, Option<CoreEvent>) {
+    match state {
+        DecoderState::WaitForHeader => {
+            if byte == HEADER_BYTE {
+                (DecoderState::Channel(Vec::default()), None)
+            }
+            else {
+                // We expected a header byte but it did not arrive, go
+                // into recovery mode
+                (DecoderState::Recover, Some(CoreEvent::MissingHeader))
+            }
+        }
+        DecoderState::Channel(mut previous_bytes) => {
+            let was_full = previous_bytes.len() >= CHANNEL_BYTE_COUNT;
+            let _ = previous_bytes.push(byte);
+            if !was_full {
+                // We are still expecting more bytes with channel values
+                (DecoderState::Channel(previous_bytes), None)
+            }
+            else {
+                // This was the last channel byte, decode channels and
+                // wait for the footer
+                (DecoderState::WaitForFooter(decode_sbus(previous_bytes)), None)
+            }
+        }
+        DecoderState::WaitForFooter(frame) => {
+            if byte == FOOTER_BYTE {
+                (DecoderState::WaitForHeader, Some(CoreEvent::Frame(frame)))
+            }
+            else {
+                // We did not get a stop byte, try to relay that error
+                (DecoderState::Recover, Some(CoreEvent::MissingFooter))
+            }
+        }
+        DecoderState::Recover => {
+            // We need to see a sequence of FOOTER->HEADER to know that
+            // we are in a valid state
+            if byte == FOOTER_BYTE {
+                (DecoderState::WaitForHeader, None)
+            }
+            else {
+                (DecoderState::Recover, None)
+            }
+        }
+    }
+}
+
+fn core_event_to_result<E>(event: CoreEvent) -> RecoverableResult<SbusFrame, E> {
+    match event {
+        CoreEvent::MissingHeader => Err(Error::MissingHeader),
+        CoreEvent::MissingFooter => Err(Error::MissingFooter),
+        CoreEvent::Frame(Ok(frame)) => Ok(frame),
+        CoreEvent::Frame(Err(Failsafe{frame})) => Err(Error::Failsafe(frame)),
+    }
+}
+
+/**
+  Encode an `SbusFrame` into the 25 bytes that make up a single SBUS
+  frame on the wire. This is the inverse of [`decode_sbus`]: the sixteen
+  11-bit channel values are packed LSB-first into the 22 data bytes,
+  channel `c` starting at bit `c * 11`.
+
+  `failsafe` sets the failsafe/frame-lost bit of the flag byte, allowing
+  a failsafe frame (as reported by [`Error::Failsafe`]) to be
+  round-tripped back out.
+*/
+pub fn encode_sbus(frame: &SbusFrame, failsafe: bool) -> [u8; 25] {
+    let mut out = [0u8; 25];
+    out[0] = HEADER_BYTE;
+
+    let mut data = [0u8; CHANNEL_BYTE_COUNT];
+    for channel in 0..CHANNEL_AMOUNT {
+        let offset = channel as u32 * 11;
+        let first_byte_index = (offset / 8) as usize;
+        let first_byte_offset = offset % 8;
+
+        let value = (frame.channels[channel as usize] & 0x07ff) as u32;
+        let shifted = value << first_byte_offset;
+
+        data[first_byte_index] |= shifted as u8;
+        data[first_byte_index + 1] |= (shifted >> 8) as u8;
+        if first_byte_offset > 5 {
+            data[first_byte_index + 2] |= (shifted >> 16) as u8;
+        }
+    }
+    out[1..1 + CHANNEL_BYTE_COUNT].copy_from_slice(&data);
+
+    let mut digital_byte = 0u8;
+    if frame.digital_channels[0] {
+        digital_byte |= 0b001;
+    }
+    if frame.digital_channels[1] {
+        digital_byte |= 0b010;
+    }
+    if failsafe {
+        digital_byte |= 0b100;
+    }
+    out[23] = digital_byte;
+    out[24] = FOOTER_BYTE;
+    out
+}
+
+/// Pushes the bytes of an `SbusFrame` onto an outgoing byte queue, the
+/// mirror image of [`SbusDecoder`] on the transmit side.
+pub struct SbusEncoder<'a> {
+    byte_tx: Producer<'a, u8, U32>,
+}
+
+impl<'a> SbusEncoder<'a> {
+    pub fn new(byte_tx: Producer<'a, u8, U32>) -> Self {
+        Self { byte_tx }
+    }
+
+    /// Encode `frame` and enqueue its bytes on the outgoing byte queue,
+    /// optionally marking it as a failsafe/frame-lost frame.
+    ///
+    /// If the queue fills up before all bytes have been enqueued, the
+    /// remaining (unsent) byte is returned as an error.
+    pub fn send(&mut self, frame: &SbusFrame, failsafe: bool) -> core::result::Result<(), u8> {
+        for byte in encode_sbus(frame, failsafe).iter() {
+            self.byte_tx.enqueue(*byte)?;
+        }
+        Ok(())
+    }
+}
+
 fn decode_sbus(bytes: Vec<u8, U23>) -> core::result::Result<SbusFrame, Failsafe> {
     let mut message = SbusFrame::default();
     for channel in 0..CHANNEL_AMOUNT {
@@ -255,6 +314,270 @@ fn decode_sbus(bytes: Vec<u8, U23>) -> core::result::Result<SbusFrame, Failsafe>
     }
 }
 
+const SBUS_FRAME_LENGTH: usize = 25;
+// How many consecutive bytes we're willing to discard while looking for
+// a header/footer pair before giving up and latching `desynced`.
+const RESYNC_LIMIT: usize = 128;
+
+/// What the bytes currently sitting in an [`SbusDeframer`]'s buffer
+/// amount to.
+enum BufferState {
+    /// Not enough bytes buffered yet to know one way or the other.
+    Partial,
+    /// The buffered bytes can't be a valid frame no matter what follows.
+    Invalid,
+    /// A full, correctly framed message is sitting at the front of the buffer.
+    Valid,
+}
+
+/**
+  An alternative front-end to [`SbusDecoder`] for transports that hand
+  back arbitrarily sized chunks of bytes (DMA buffers, block reads, ...)
+  instead of one byte at a time.
+
+  Bytes are accumulated into a fixed-size buffer with [`push`](Self::push)
+  until a full frame (or an unrecoverable framing error) can be pulled off
+  the front, mirroring the buffering `MessageDeframer` pattern used by
+  other binary protocol implementations.
+*/
+pub struct SbusDeframer {
+    buf: [u8; SBUS_FRAME_LENGTH],
+    used: usize,
+    dropped_since_valid: usize,
+    desynced: bool,
+}
+
+impl Default for SbusDeframer {
+    fn default() -> Self {
+        Self {
+            buf: [0; SBUS_FRAME_LENGTH],
+            used: 0,
+            dropped_since_valid: 0,
+            desynced: false,
+        }
+    }
+}
+
+impl SbusDeframer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set once framing could not be resynchronized after
+    /// [`RESYNC_LIMIT`] bytes were discarded. Once set, the caller should
+    /// give up on this stream rather than keep pushing bytes into it.
+    pub fn desynced(&self) -> bool {
+        self.desynced
+    }
+
+    /// Append as much of `data` as fits in the accumulation buffer,
+    /// pulling complete frames (or framing errors) out into `out` as
+    /// they become available.
+    pub fn push<N>(
+        &mut self,
+        mut data: &[u8],
+        out: &mut Vec<RecoverableResult<SbusFrame, ()>, N>
+    )
+        where N: heapless::ArrayLength<RecoverableResult<SbusFrame, ()>>
+    {
+        while !data.is_empty() {
+            let free = self.buf.len() - self.used;
+            let take = free.min(data.len());
+            self.buf[self.used..self.used + take].copy_from_slice(&data[..take]);
+            self.used += take;
+            data = &data[take..];
+
+            while self.try_extract_one(out) {}
+        }
+    }
+
+    // Try to classify and, if possible, consume one frame or one
+    // erroneous byte from the front of the buffer. Returns whether
+    // anything was consumed, so the caller can keep looping.
+    fn try_extract_one<N>(&mut self, out: &mut Vec<RecoverableResult<SbusFrame, ()>, N>) -> bool
+        where N: heapless::ArrayLength<RecoverableResult<SbusFrame, ()>>
+    {
+        match self.classify() {
+            BufferState::Partial => false,
+            BufferState::Invalid => {
+                let _ = out.push(Err(Error::ExpectedHeader));
+                self.compact(1);
+                self.dropped_since_valid += 1;
+                if self.dropped_since_valid > RESYNC_LIMIT {
+                    self.desynced = true;
+                }
+                true
+            }
+            BufferState::Valid => {
+                let mut channel_bytes = Vec::<u8, U23>::new();
+                let _ = channel_bytes.extend_from_slice(&self.buf[1..1 + CHANNEL_BYTE_COUNT + 1]);
+                let result = match decode_sbus(channel_bytes) {
+                    Ok(frame) => Ok(frame),
+                    Err(Failsafe{frame}) => Err(Error::Failsafe(frame)),
+                };
+                let _ = out.push(result);
+                self.compact(SBUS_FRAME_LENGTH);
+                self.dropped_since_valid = 0;
+                true
+            }
+        }
+    }
+
+    fn classify(&self) -> BufferState {
+        if self.used == 0 {
+            return BufferState::Partial;
+        }
+        if self.buf[0] != HEADER_BYTE {
+            return BufferState::Invalid;
+        }
+        if self.used < SBUS_FRAME_LENGTH {
+            return BufferState::Partial;
+        }
+        if self.buf[SBUS_FRAME_LENGTH - 1] != FOOTER_BYTE {
+            return BufferState::Invalid;
+        }
+        BufferState::Valid
+    }
+
+    // Drop `n` bytes from the front of the buffer, shifting the rest down.
+    fn compact(&mut self, n: usize) {
+        let n = n.min(self.used);
+        self.buf.copy_within(n..self.used, 0);
+        self.used -= n;
+    }
+}
+
+/// Desktop-only adapter driving the decode logic over a [`std::io::Read`]
+/// source, for tooling that has no use for the embedded spsc queues.
+#[cfg(feature = "std")]
+mod std_support {
+    use super::{
+        CHANNEL_BYTE_COUNT, Error, FOOTER_BYTE, Failsafe, HEADER_BYTE, RecoverableResult,
+        SbusFrame, Vec, decode_sbus,
+    };
+    use heapless::consts::U23;
+    use std::io::{ErrorKind, Read};
+
+    struct ReadFrames<R> {
+        reader: R,
+    }
+
+    impl<R: Read> Iterator for ReadFrames<R> {
+        type Item = RecoverableResult<SbusFrame, std::io::Error>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let mut byte = [0u8; 1];
+            loop {
+                match self.reader.read_exact(&mut byte) {
+                    Ok(()) => {}
+                    Err(e) if e.kind() == ErrorKind::UnexpectedEof => return None,
+                    Err(e) => return Some(Err(Error::ByteReadError(e))),
+                }
+                if byte[0] == HEADER_BYTE {
+                    break;
+                }
+            }
+
+            let mut channel_bytes = [0u8; CHANNEL_BYTE_COUNT + 1];
+            if let Err(e) = self.reader.read_exact(&mut channel_bytes) {
+                return Some(Err(Error::ByteReadError(e)));
+            }
+
+            let mut footer = [0u8; 1];
+            if let Err(e) = self.reader.read_exact(&mut footer) {
+                return Some(Err(Error::ByteReadError(e)));
+            }
+            if footer[0] != FOOTER_BYTE {
+                return Some(Err(Error::MissingFooter));
+            }
+
+            let mut bytes = Vec::<u8, U23>::new();
+            bytes.extend_from_slice(&channel_bytes).unwrap();
+            Some(match decode_sbus(bytes) {
+                Ok(frame) => Ok(frame),
+                Err(Failsafe { frame }) => Err(Error::Failsafe(frame)),
+            })
+        }
+    }
+
+    /// Decode `input` as a stream of SBUS frames, yielding one decoded
+    /// (or erroneous) [`SbusFrame`] per iteration. Desynchronized bytes
+    /// preceding a header are skipped over rather than surfaced, since a
+    /// `Read` source has no notion of "recovery" to report back.
+    pub fn iter_frames<R: Read>(
+        input: R
+    ) -> impl Iterator<Item = RecoverableResult<SbusFrame, std::io::Error>> {
+        ReadFrames { reader: input }
+    }
+}
+
+#[cfg(feature = "std")]
+pub use std_support::iter_frames;
+
+/// Async adapter for executors (e.g. embassy) whose UART exposes an
+/// async byte source rather than a timer-fed spsc queue.
+#[cfg(feature = "async")]
+mod async_support {
+    use super::{DecoderState, SbusFrame, RecoverableResult, advance, core_event_to_result};
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+    use futures::io::AsyncRead;
+    use futures::Stream;
+
+    /**
+      Pumps the same [`DecoderState`] machine as [`super::SbusDecoder`],
+      but awaits bytes from an [`AsyncRead`] source instead of polling
+      an spsc `Consumer`. Recovery semantics (header/footer resync,
+      failsafe reporting) are identical between the two front-ends,
+      since both drive the same [`advance`] step.
+    */
+    pub struct AsyncSbusDecoder<R> {
+        reader: R,
+        state: DecoderState,
+    }
+
+    impl<R: AsyncRead + Unpin> AsyncSbusDecoder<R> {
+        pub fn new(reader: R) -> Self {
+            Self {
+                reader,
+                state: DecoderState::WaitForHeader,
+            }
+        }
+    }
+
+    impl<R: AsyncRead + Unpin> Stream for AsyncSbusDecoder<R> {
+        type Item = RecoverableResult<SbusFrame, futures::io::Error>;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let this = self.get_mut();
+            loop {
+                let mut byte = [0u8; 1];
+                let read = match Pin::new(&mut this.reader).poll_read(cx, &mut byte) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Ok(read)) => read,
+                    Poll::Ready(Err(e)) => {
+                        this.state = DecoderState::Recover;
+                        return Poll::Ready(Some(Err(super::Error::ByteReadError(e))));
+                    }
+                };
+                // The underlying source is exhausted.
+                if read == 0 {
+                    return Poll::Ready(None);
+                }
+
+                let (new_state, event) = advance(this.state.clone(), byte[0]);
+                this.state = new_state;
+                if let Some(event) = event {
+                    return Poll::Ready(Some(core_event_to_result::<futures::io::Error>(event)));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub use async_support::AsyncSbusDecoder;
+
 
 
 #[cfg(test)]
@@ -507,4 +830,308 @@ mod tests {
         let decoded = message_consumer.dequeue();
         assert_eq!(decoded, Some(Err(Error::Failsafe(expected_frame))));
     }
+
+    #[test]
+    fn encode_sbus_round_trips_through_decode_sbus() {
+        let frame = SbusFrame {
+            channels: [
+                0b111_1111_1110,
+                0,
+                0b111_1111_1111,
+                0,
+                0b111_1111_1111,
+                0,
+                0b111_1111_1111,
+                0,
+                0b111_1111_1111,
+                0,
+                0b111_1111_1111,
+                0,
+                0b111_1111_1111,
+                0,
+                0b111_1111_1111,
+                0,
+            ],
+            digital_channels: [true, true],
+        };
+
+        let bytes = encode_sbus(&frame, false);
+        assert_eq!(bytes[0], HEADER_BYTE);
+        assert_eq!(bytes[24], FOOTER_BYTE);
+
+        let mut data = Vec::<u8, U23>::new();
+        data.extend_from_slice(&bytes[1..24]).unwrap();
+
+        assert_eq!(decode_sbus(data).expect("Expected Ok frame"), frame);
+    }
+
+    #[test]
+    fn encode_sbus_matches_decoder_fixture() {
+        let bytes: [u8; 25] = [
+            0x0f,
+            0b1111_1110,
+            0b0000_0111,
+            0b1100_0000,
+            0b1111_1111,
+            0b0000_0001,
+            0b1111_0000,
+            0b0111_1111,
+            0b0000_0000,
+            0b1111_1100,
+            0b0001_1111,
+            0b0000_0000,
+            0b1111_1111,
+            0b0000_0111,
+            0b1100_0000,
+            0b1111_1111,
+            0b0000_0001,
+            0b1111_0000,
+            0b0111_1111,
+            0b0000_0000,
+            0b1111_1100,
+            0b0001_1111,
+            0b0000_0000,
+            0b0000_0011,
+            0b0000_0000,
+        ];
+
+        let frame = SbusFrame {
+            channels: [
+                0b111_1111_1110,
+                0,
+                0b111_1111_1111,
+                0,
+                0b111_1111_1111,
+                0,
+                0b111_1111_1111,
+                0,
+                0b111_1111_1111,
+                0,
+                0b111_1111_1111,
+                0,
+                0b111_1111_1111,
+                0,
+                0b111_1111_1111,
+                0,
+            ],
+            digital_channels: [true, true],
+        };
+
+        assert_eq!(encode_sbus(&frame, false), bytes);
+    }
+
+    #[test]
+    fn encode_sbus_sets_the_failsafe_bit() {
+        let frame = SbusFrame::default();
+
+        let bytes = encode_sbus(&frame, true);
+        assert_eq!(bytes[23] & 0b100, 0b100);
+
+        let mut data = Vec::<u8, U23>::new();
+        data.extend_from_slice(&bytes[1..24]).unwrap();
+        assert_matches!(decode_sbus(data), Err(Failsafe { .. }));
+    }
+
+    #[test]
+    fn deframer_extracts_a_frame_split_across_several_pushes() {
+        let bytes: [u8; 25] = [
+            0x0f,
+            0b1111_1110,
+            0b0000_0111,
+            0b1100_0000,
+            0b1111_1111,
+            0b0000_0001,
+            0b1111_0000,
+            0b0111_1111,
+            0b0000_0000,
+            0b1111_1100,
+            0b0001_1111,
+            0b0000_0000,
+            0b1111_1111,
+            0b0000_0111,
+            0b1100_0000,
+            0b1111_1111,
+            0b0000_0001,
+            0b1111_0000,
+            0b0111_1111,
+            0b0000_0000,
+            0b1111_1100,
+            0b0001_1111,
+            0b0000_0000,
+            0b0000_0011,
+            0b0000_0000,
+        ];
+
+        let mut deframer = SbusDeframer::new();
+        let mut out = Vec::<RecoverableResult<SbusFrame, ()>, U8>::new();
+
+        deframer.push(&bytes[0..10], &mut out);
+        assert!(out.is_empty());
+        deframer.push(&bytes[10..], &mut out);
+
+        assert_eq!(out.len(), 1);
+        let frame = out[0].as_ref().expect("Expected a decoded frame");
+        assert_eq!(frame.digital_channels, [true, true]);
+        assert!(!deframer.desynced());
+    }
+
+    #[test]
+    fn deframer_resyncs_after_garbage_bytes() {
+        let mut garbage: Vec<u8, U32> = Vec::new();
+        garbage.extend_from_slice(&[1, 2, 3]).unwrap();
+
+        let bytes: [u8; 25] = [
+            0x0f,
+            0b1111_1110,
+            0b0000_0111,
+            0b1100_0000,
+            0b1111_1111,
+            0b0000_0001,
+            0b1111_0000,
+            0b0111_1111,
+            0b0000_0000,
+            0b1111_1100,
+            0b0001_1111,
+            0b0000_0000,
+            0b1111_1111,
+            0b0000_0111,
+            0b1100_0000,
+            0b1111_1111,
+            0b0000_0001,
+            0b1111_0000,
+            0b0111_1111,
+            0b0000_0000,
+            0b1111_1100,
+            0b0001_1111,
+            0b0000_0000,
+            0b0000_0011,
+            0b0000_0000,
+        ];
+        garbage.extend_from_slice(&bytes).unwrap();
+
+        let mut deframer = SbusDeframer::new();
+        let mut out = Vec::<RecoverableResult<SbusFrame, ()>, U32>::new();
+
+        deframer.push(&garbage, &mut out);
+
+        assert_matches!(out.last(), Some(Ok(_)));
+        assert!(!deframer.desynced());
+    }
+
+    #[test]
+    fn deframer_latches_desynced_when_resync_limit_is_exceeded() {
+        let garbage = [1u8; RESYNC_LIMIT + 10];
+
+        let mut deframer = SbusDeframer::new();
+        let mut out = Vec::<RecoverableResult<SbusFrame, ()>, U64>::new();
+
+        deframer.push(&garbage, &mut out);
+
+        assert!(deframer.desynced());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn iter_frames_decodes_frames_from_a_read_source() {
+        let bytes: [u8; 25] = [
+            0x0f,
+            0b1111_1110,
+            0b0000_0111,
+            0b1100_0000,
+            0b1111_1111,
+            0b0000_0001,
+            0b1111_0000,
+            0b0111_1111,
+            0b0000_0000,
+            0b1111_1100,
+            0b0001_1111,
+            0b0000_0000,
+            0b1111_1111,
+            0b0000_0111,
+            0b1100_0000,
+            0b1111_1111,
+            0b0000_0001,
+            0b1111_0000,
+            0b0111_1111,
+            0b0000_0000,
+            0b1111_1100,
+            0b0001_1111,
+            0b0000_0000,
+            0b0000_0011,
+            0b0000_0000,
+        ];
+
+        let cursor = std::io::Cursor::new(bytes.to_vec());
+        let mut frames = iter_frames(cursor);
+
+        let frame = frames.next()
+            .expect("Expected an item")
+            .expect("Expected a decoded frame");
+        assert_eq!(frame.digital_channels, [true, true]);
+
+        assert!(frames.next().is_none());
+    }
+
+    #[cfg(feature = "async")]
+    struct SliceReader<'a> {
+        remaining: &'a [u8],
+    }
+
+    #[cfg(feature = "async")]
+    impl<'a> futures::io::AsyncRead for SliceReader<'a> {
+        fn poll_read(
+            mut self: core::pin::Pin<&mut Self>,
+            _cx: &mut core::task::Context<'_>,
+            buf: &mut [u8]
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            let take = buf.len().min(self.remaining.len());
+            buf[..take].copy_from_slice(&self.remaining[..take]);
+            self.remaining = &self.remaining[take..];
+            core::task::Poll::Ready(Ok(take))
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "async")]
+    fn async_sbus_decoder_decodes_frames_from_an_async_reader() {
+        use futures::StreamExt;
+
+        let bytes: [u8; 25] = [
+            0x0f,
+            0b1111_1110,
+            0b0000_0111,
+            0b1100_0000,
+            0b1111_1111,
+            0b0000_0001,
+            0b1111_0000,
+            0b0111_1111,
+            0b0000_0000,
+            0b1111_1100,
+            0b0001_1111,
+            0b0000_0000,
+            0b1111_1111,
+            0b0000_0111,
+            0b1100_0000,
+            0b1111_1111,
+            0b0000_0001,
+            0b1111_0000,
+            0b0111_1111,
+            0b0000_0000,
+            0b1111_1100,
+            0b0001_1111,
+            0b0000_0000,
+            0b0000_0011,
+            0b0000_0000,
+        ];
+
+        let reader = SliceReader { remaining: &bytes };
+        let mut decoder = async_support::AsyncSbusDecoder::new(reader);
+
+        let frame = futures::executor::block_on(decoder.next())
+            .expect("Expected an item")
+            .expect("Expected a decoded frame");
+        assert_eq!(frame.digital_channels, [true, true]);
+
+        assert!(futures::executor::block_on(decoder.next()).is_none());
+    }
 }